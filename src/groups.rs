@@ -58,6 +58,8 @@ impl<'a> Iterator for SimpleNodes<'a> {
             id: NodeId(n.get_id()),
             decimicro_lat: make_lat(n.get_lat(), self.block),
             decimicro_lon: make_lon(n.get_lon(), self.block),
+            nanodeg_lat: nanodeg_lat(n.get_lat(), self.block),
+            nanodeg_lon: nanodeg_lon(n.get_lon(), self.block),
             tags: make_tags(n.get_keys(), n.get_vals(), self.block),
             info: if n.has_info() {
                 make_info(n.get_info(), self.block)
@@ -202,6 +204,8 @@ impl<'a> Iterator for DenseNodes<'a> {
             id: NodeId(self.cur_id),
             decimicro_lat: make_lat(self.cur_lat, self.block),
             decimicro_lon: make_lon(self.cur_lon, self.block),
+            nanodeg_lat: nanodeg_lat(self.cur_lat, self.block),
+            nanodeg_lon: nanodeg_lon(self.cur_lon, self.block),
             tags: tags,
             info,
         })
@@ -257,6 +261,529 @@ impl<'a> Iterator for Ways<'a> {
     }
 }
 
+pub_iterator_type! {
+    #[doc="Iterator on the `OsmObjRef` of a `PrimitiveGroup`, borrowing strings directly from the block's string table instead of allocating."]
+    OsmObjsRef['a] = Chain<Chain<Map<NodesRef<'a>, fn(NodeRef<'a>) -> OsmObjRef<'a>>,
+                                 Map<WaysRef<'a>, fn(WayRef<'a>) -> OsmObjRef<'a>>>,
+                           Map<RelationsRef<'a>, fn(RelationRef<'a>) -> OsmObjRef<'a>>>
+}
+
+/// Like [`iter`], but borrows strings from the block instead of allocating.
+pub fn iter_borrowed<'a>(g: &'a PrimitiveGroup, b: &'a PrimitiveBlock) -> OsmObjsRef<'a> {
+    let iter = nodes_borrowed(g, b)
+        .map(From::from as fn(NodeRef<'a>) -> OsmObjRef<'a>)
+        .chain(ways_borrowed(g, b).map(From::from as fn(WayRef<'a>) -> OsmObjRef<'a>))
+        .chain(relations_borrowed(g, b).map(From::from as fn(RelationRef<'a>) -> OsmObjRef<'a>));
+    OsmObjsRef(iter)
+}
+
+/// Borrowed counterpart of [`OsmObj`], see [`iter_borrowed`].
+pub enum OsmObjRef<'a> {
+    Node(NodeRef<'a>),
+    Way(WayRef<'a>),
+    Relation(RelationRef<'a>),
+}
+
+impl<'a> From<NodeRef<'a>> for OsmObjRef<'a> {
+    fn from(n: NodeRef<'a>) -> Self {
+        OsmObjRef::Node(n)
+    }
+}
+impl<'a> From<WayRef<'a>> for OsmObjRef<'a> {
+    fn from(w: WayRef<'a>) -> Self {
+        OsmObjRef::Way(w)
+    }
+}
+impl<'a> From<RelationRef<'a>> for OsmObjRef<'a> {
+    fn from(r: RelationRef<'a>) -> Self {
+        OsmObjRef::Relation(r)
+    }
+}
+
+/// Borrowed view over a node's, way's or relation's tags, see [`iter_borrowed`].
+pub enum TagsRef<'a> {
+    Pairs {
+        keys: &'a [u32],
+        vals: &'a [u32],
+        block: &'a PrimitiveBlock,
+    },
+    Interleaved {
+        keys_vals: &'a [i32],
+        block: &'a PrimitiveBlock,
+    },
+}
+
+impl<'a> TagsRef<'a> {
+    pub fn iter(&self) -> TagsRefIter<'a> {
+        match *self {
+            TagsRef::Pairs { keys, vals, block } => TagsRefIter::Pairs {
+                keys: keys.iter(),
+                vals: vals.iter(),
+                block,
+            },
+            TagsRef::Interleaved { keys_vals, block } => TagsRefIter::Interleaved {
+                iter: keys_vals.iter(),
+                block,
+            },
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'_ TagsRef<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+    type IntoIter = TagsRefIter<'a>;
+    fn into_iter(self) -> TagsRefIter<'a> {
+        self.iter()
+    }
+}
+
+pub enum TagsRefIter<'a> {
+    Pairs {
+        keys: slice::Iter<'a, u32>,
+        vals: slice::Iter<'a, u32>,
+        block: &'a PrimitiveBlock,
+    },
+    Interleaved {
+        iter: slice::Iter<'a, i32>,
+        block: &'a PrimitiveBlock,
+    },
+}
+
+impl<'a> Iterator for TagsRefIter<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            TagsRefIter::Pairs { keys, vals, block } => match (keys.next(), vals.next()) {
+                (Some(&k), Some(&v)) => Some((
+                    make_string_ref(k as usize, block),
+                    make_string_ref(v as usize, block),
+                )),
+                _ => None,
+            },
+            TagsRefIter::Interleaved { iter, block } => {
+                let k = match iter.next() {
+                    Some(&0) | None => return None,
+                    Some(&k) => k,
+                };
+                let v = match iter.next() {
+                    Some(&v) => v,
+                    None => return None,
+                };
+                Some((make_string_ref(k as usize, block), make_string_ref(v as usize, block)))
+            }
+        }
+    }
+}
+
+/// Borrowed counterpart of [`Info`], see [`iter_borrowed`].
+pub struct InfoRef<'a> {
+    pub version: Option<i32>,
+    pub timestamp: Option<i64>,
+    pub changeset: Option<i64>,
+    pub uid: Option<i32>,
+    pub user: Option<Cow<'a, str>>,
+    pub visible: Option<bool>,
+}
+
+/// Borrowed counterpart of [`Node`], see [`iter_borrowed`]. Also carries the
+/// full-precision `nanodeg_lat`/`nanodeg_lon` coordinates for callers that
+/// need exact positions instead of the rounded decimicrodegree fields.
+pub struct NodeRef<'a> {
+    pub id: NodeId,
+    pub decimicro_lat: i32,
+    pub decimicro_lon: i32,
+    pub nanodeg_lat: i64,
+    pub nanodeg_lon: i64,
+    pub tags: TagsRef<'a>,
+    pub info: InfoRef<'a>,
+}
+
+impl<'a> NodeRef<'a> {
+    /// Exact latitude in degrees, computed from `nanodeg_lat`.
+    pub fn lat_f64(&self) -> f64 {
+        self.nanodeg_lat as f64 / 1_000_000_000.0
+    }
+
+    /// Exact longitude in degrees, computed from `nanodeg_lon`.
+    pub fn lon_f64(&self) -> f64 {
+        self.nanodeg_lon as f64 / 1_000_000_000.0
+    }
+}
+
+pub_iterator_type! {
+    #[doc="Iterator on the `NodeRef` of a `PrimitiveGroup`."]
+    NodesRef['a] = Chain<SimpleNodesRef<'a>, DenseNodesRef<'a>>
+}
+
+pub fn nodes_borrowed<'a>(g: &'a PrimitiveGroup, b: &'a PrimitiveBlock) -> NodesRef<'a> {
+    NodesRef(simple_nodes_borrowed(g, b).chain(dense_nodes_borrowed(g, b)))
+}
+
+pub fn simple_nodes_borrowed<'a>(
+    group: &'a PrimitiveGroup,
+    block: &'a PrimitiveBlock,
+) -> SimpleNodesRef<'a> {
+    SimpleNodesRef {
+        iter: group.get_nodes().iter(),
+        block,
+    }
+}
+
+pub struct SimpleNodesRef<'a> {
+    iter: slice::Iter<'a, osmformat::Node>,
+    block: &'a PrimitiveBlock,
+}
+
+impl<'a> Iterator for SimpleNodesRef<'a> {
+    type Item = NodeRef<'a>;
+    fn next(&mut self) -> Option<NodeRef<'a>> {
+        self.iter.next().map(|n| NodeRef {
+            id: NodeId(n.get_id()),
+            decimicro_lat: make_lat(n.get_lat(), self.block),
+            decimicro_lon: make_lon(n.get_lon(), self.block),
+            nanodeg_lat: nanodeg_lat(n.get_lat(), self.block),
+            nanodeg_lon: nanodeg_lon(n.get_lon(), self.block),
+            tags: TagsRef::Pairs {
+                keys: n.get_keys(),
+                vals: n.get_vals(),
+                block: self.block,
+            },
+            info: if n.has_info() {
+                make_info_ref(n.get_info(), self.block)
+            } else {
+                InfoRef {
+                    version: None,
+                    timestamp: None,
+                    changeset: None,
+                    uid: None,
+                    user: None,
+                    visible: None,
+                }
+            },
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+struct DenseInfoIterRef<'a> {
+    denseinfo: &'a protobuf::SingularPtrField<osmformat::DenseInfo>,
+    block: &'a osmformat::PrimitiveBlock,
+    cur_timestamp: i64,
+    cur_changeset: i64,
+    cur_uid: i32,
+    cur_user_sid: i32,
+    place: usize,
+}
+
+impl<'a> DenseInfoIterRef<'a> {
+    fn new(
+        denseinfo: &'a protobuf::SingularPtrField<osmformat::DenseInfo>,
+        block: &'a osmformat::PrimitiveBlock,
+    ) -> Self {
+        Self {
+            denseinfo,
+            block,
+            cur_timestamp: 0,
+            cur_changeset: 0,
+            cur_uid: 0,
+            cur_user_sid: 0,
+            place: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for DenseInfoIterRef<'a> {
+    type Item = InfoRef<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.denseinfo.is_some() {
+            let di = self.denseinfo.get_ref();
+            if self.place == di.version.len() {
+                return None;
+            }
+            let version = di.version[self.place];
+            self.cur_timestamp += di.timestamp[self.place];
+            self.cur_changeset += di.changeset[self.place];
+            self.cur_uid += di.uid[self.place];
+            self.cur_user_sid += di.user_sid[self.place];
+            let visible = di.visible[self.place];
+            self.place += 1;
+            Some(InfoRef {
+                version: Some(version),
+                timestamp: Some(self.cur_timestamp),
+                changeset: Some(self.cur_changeset),
+                uid: Some(self.cur_uid),
+                user: Some(make_string_ref(self.cur_user_sid as usize, self.block)),
+                visible: Some(visible),
+            })
+        } else {
+            Some(InfoRef {
+                version: None,
+                timestamp: None,
+                changeset: None,
+                uid: None,
+                user: None,
+                visible: None,
+            })
+        }
+    }
+}
+
+/// Index just past the tags of the dense node starting at `start` in the
+/// interleaved `key, val, key, val, ..., 0` run, clamped to `keys_vals.len()`
+/// so a malformed/truncated block with no terminator can't overshoot it.
+fn dense_tag_span_end(keys_vals: &[i32], start: usize) -> usize {
+    let mut pos = start;
+    while pos < keys_vals.len() && keys_vals[pos] != 0 {
+        pos += 2;
+    }
+    pos.min(keys_vals.len())
+}
+
+pub fn dense_nodes_borrowed<'a>(
+    group: &'a PrimitiveGroup,
+    block: &'a PrimitiveBlock,
+) -> DenseNodesRef<'a> {
+    let dense = group.get_dense();
+    DenseNodesRef {
+        block,
+        dids: dense.get_id().iter(),
+        dlats: dense.get_lat().iter(),
+        dlons: dense.get_lon().iter(),
+        keys_vals: dense.get_keys_vals(),
+        kv_pos: 0,
+        cur_id: 0,
+        cur_lat: 0,
+        cur_lon: 0,
+        denseinfo: DenseInfoIterRef::new(&dense.denseinfo, block),
+    }
+}
+
+pub struct DenseNodesRef<'a> {
+    block: &'a PrimitiveBlock,
+    dids: slice::Iter<'a, i64>,
+    dlats: slice::Iter<'a, i64>,
+    dlons: slice::Iter<'a, i64>,
+    keys_vals: &'a [i32],
+    kv_pos: usize,
+    cur_id: i64,
+    cur_lat: i64,
+    cur_lon: i64,
+    denseinfo: DenseInfoIterRef<'a>,
+}
+
+impl<'a> Iterator for DenseNodesRef<'a> {
+    type Item = NodeRef<'a>;
+    fn next(&mut self) -> Option<NodeRef<'a>> {
+        let info = match (
+            self.dids.next(),
+            self.dlats.next(),
+            self.dlons.next(),
+            self.denseinfo.next(),
+        ) {
+            (Some(&did), Some(&dlat), Some(&dlon), Some(info)) => {
+                self.cur_id += did;
+                self.cur_lat += dlat;
+                self.cur_lon += dlon;
+                info
+            }
+            _ => return None,
+        };
+        let start = self.kv_pos;
+        let end = dense_tag_span_end(self.keys_vals, start);
+        self.kv_pos = if end < self.keys_vals.len() { end + 1 } else { end };
+        Some(NodeRef {
+            id: NodeId(self.cur_id),
+            decimicro_lat: make_lat(self.cur_lat, self.block),
+            decimicro_lon: make_lon(self.cur_lon, self.block),
+            nanodeg_lat: nanodeg_lat(self.cur_lat, self.block),
+            nanodeg_lon: nanodeg_lon(self.cur_lon, self.block),
+            tags: TagsRef::Interleaved {
+                keys_vals: &self.keys_vals[start..end],
+                block: self.block,
+            },
+            info,
+        })
+    }
+}
+
+/// Borrowed counterpart of [`Way`], see [`iter_borrowed`]. `refs` holds the
+/// raw delta-encoded node references; use [`WayRef::nodes`] to decode them
+/// lazily without allocating a `Vec`.
+pub struct WayRef<'a> {
+    pub id: WayId,
+    pub refs: &'a [i64],
+    pub tags: TagsRef<'a>,
+    pub info: InfoRef<'a>,
+}
+
+impl<'a> WayRef<'a> {
+    pub fn nodes(&self) -> WayNodeIdsRef<'a> {
+        WayNodeIdsRef {
+            iter: self.refs.iter(),
+            cur: 0,
+        }
+    }
+}
+
+pub struct WayNodeIdsRef<'a> {
+    iter: slice::Iter<'a, i64>,
+    cur: i64,
+}
+
+impl<'a> Iterator for WayNodeIdsRef<'a> {
+    type Item = NodeId;
+    fn next(&mut self) -> Option<NodeId> {
+        self.iter.next().map(|&dn| {
+            self.cur += dn;
+            NodeId(self.cur)
+        })
+    }
+}
+
+pub fn ways_borrowed<'a>(group: &'a PrimitiveGroup, block: &'a PrimitiveBlock) -> WaysRef<'a> {
+    WaysRef {
+        iter: group.get_ways().iter(),
+        block,
+    }
+}
+
+pub struct WaysRef<'a> {
+    iter: slice::Iter<'a, osmformat::Way>,
+    block: &'a PrimitiveBlock,
+}
+
+impl<'a> Iterator for WaysRef<'a> {
+    type Item = WayRef<'a>;
+    fn next(&mut self) -> Option<WayRef<'a>> {
+        self.iter.next().map(|w| WayRef {
+            id: WayId(w.get_id()),
+            refs: w.get_refs(),
+            tags: TagsRef::Pairs {
+                keys: w.get_keys(),
+                vals: w.get_vals(),
+                block: self.block,
+            },
+            info: if w.has_info() {
+                make_info_ref(w.get_info(), self.block)
+            } else {
+                InfoRef {
+                    version: None,
+                    timestamp: None,
+                    changeset: None,
+                    uid: None,
+                    user: None,
+                    visible: None,
+                }
+            },
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Borrowed counterpart of [`Relation`], see [`iter_borrowed`]. `memids`,
+/// `types`, and `roles_sid` hold the raw parallel arrays; use
+/// [`RelationRef::refs`] to decode them lazily without allocating a `Vec`.
+pub struct RelationRef<'a> {
+    pub id: RelationId,
+    pub memids: &'a [i64],
+    pub types: &'a [osmformat::Relation_MemberType],
+    pub roles_sid: &'a [i32],
+    pub tags: TagsRef<'a>,
+    pub info: InfoRef<'a>,
+    block: &'a PrimitiveBlock,
+}
+
+impl<'a> RelationRef<'a> {
+    pub fn refs(&self) -> RefsRef<'a> {
+        RefsRef {
+            memids: self.memids.iter(),
+            types: self.types.iter(),
+            roles_sid: self.roles_sid.iter(),
+            cur: 0,
+            block: self.block,
+        }
+    }
+}
+
+pub struct RefsRef<'a> {
+    memids: slice::Iter<'a, i64>,
+    types: slice::Iter<'a, osmformat::Relation_MemberType>,
+    roles_sid: slice::Iter<'a, i32>,
+    cur: i64,
+    block: &'a PrimitiveBlock,
+}
+
+impl<'a> Iterator for RefsRef<'a> {
+    type Item = (OsmId, Cow<'a, str>);
+    fn next(&mut self) -> Option<Self::Item> {
+        use osmformat::Relation_MemberType::{NODE, RELATION, WAY};
+        match (self.memids.next(), self.types.next(), self.roles_sid.next()) {
+            (Some(&dm), Some(&t), Some(&role)) => {
+                self.cur += dm;
+                let member = match t {
+                    NODE => NodeId(self.cur).into(),
+                    WAY => WayId(self.cur).into(),
+                    RELATION => RelationId(self.cur).into(),
+                };
+                Some((member, make_string_ref(role as usize, self.block)))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn relations_borrowed<'a>(
+    group: &'a PrimitiveGroup,
+    block: &'a PrimitiveBlock,
+) -> RelationsRef<'a> {
+    RelationsRef {
+        iter: group.get_relations().iter(),
+        block,
+    }
+}
+
+pub struct RelationsRef<'a> {
+    iter: slice::Iter<'a, osmformat::Relation>,
+    block: &'a PrimitiveBlock,
+}
+
+impl<'a> Iterator for RelationsRef<'a> {
+    type Item = RelationRef<'a>;
+    fn next(&mut self) -> Option<RelationRef<'a>> {
+        self.iter.next().map(|rel| RelationRef {
+            id: RelationId(rel.get_id()),
+            memids: rel.get_memids(),
+            types: rel.get_types(),
+            roles_sid: rel.get_roles_sid(),
+            tags: TagsRef::Pairs {
+                keys: rel.get_keys(),
+                vals: rel.get_vals(),
+                block: self.block,
+            },
+            info: if rel.has_info() {
+                make_info_ref(rel.get_info(), self.block)
+            } else {
+                InfoRef {
+                    version: None,
+                    timestamp: None,
+                    changeset: None,
+                    uid: None,
+                    user: None,
+                    visible: None,
+                }
+            },
+            block: self.block,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 pub fn relations<'a>(group: &'a PrimitiveGroup, block: &'a PrimitiveBlock) -> Relations<'a> {
     Relations {
         iter: group.get_relations().iter(),
@@ -323,14 +850,74 @@ fn make_string(k: usize, block: &osmformat::PrimitiveBlock) -> String {
     }
 }
 
+/// Like `make_string`, but borrows instead of allocating, see [`iter_borrowed`].
+/// Only bytes that are not valid UTF-8 are copied into an owned, lossily
+/// converted string.
+fn make_string_ref<'a>(k: usize, block: &'a osmformat::PrimitiveBlock) -> Cow<'a, str> {
+    std::string::String::from_utf8_lossy(&*block.get_stringtable().get_s()[k])
+}
+
+fn make_info_ref<'a>(i: &'a osmformat::Info, b: &'a PrimitiveBlock) -> InfoRef<'a> {
+    let version = if i.has_version() {
+        Some(i.get_version())
+    } else {
+        None
+    };
+    let timestamp = if i.has_timestamp() {
+        Some(i.get_timestamp())
+    } else {
+        None
+    };
+    let changeset = if i.has_changeset() {
+        Some(i.get_changeset())
+    } else {
+        None
+    };
+    let uid = if i.has_uid() { Some(i.get_uid()) } else { None };
+    let user = if i.has_user_sid() {
+        Some(make_string_ref(i.get_user_sid() as usize, b))
+    } else {
+        None
+    };
+    let visible = if i.has_visible() {
+        Some(i.get_visible())
+    } else {
+        None
+    };
+    InfoRef {
+        version,
+        timestamp,
+        changeset,
+        uid,
+        user,
+        visible,
+    }
+}
+
+/// Full-precision latitude in nanodegrees (`lat_offset + granularity * c`),
+/// with no division, exact for any block granularity unlike `decimicro_lat`.
+/// Backs `Node::nanodeg_lat()` and the `NodeRef::nanodeg_lat` field.
+pub fn nanodeg_lat(c: i64, b: &osmformat::PrimitiveBlock) -> i64 {
+    b.get_lat_offset() + b.get_granularity() as i64 * c
+}
+
+/// Full-precision longitude in nanodegrees, see [`nanodeg_lat`].
+pub fn nanodeg_lon(c: i64, b: &osmformat::PrimitiveBlock) -> i64 {
+    b.get_lon_offset() + b.get_granularity() as i64 * c
+}
+
+/// Rounds a nanodegree value to the nearest decimicrodegree (1e-7 degree),
+/// rounding exact ties away from zero, instead of truncating towards zero.
+fn round_nanodeg_to_decimicro(n: i64) -> i32 {
+    (if n >= 0 { n + 50 } else { n - 50 } / 100) as i32
+}
+
 fn make_lat(c: i64, b: &osmformat::PrimitiveBlock) -> i32 {
-    let granularity = b.get_granularity() as i64;
-    ((b.get_lat_offset() + granularity * c) / 100) as i32
+    round_nanodeg_to_decimicro(nanodeg_lat(c, b))
 }
 
 fn make_lon(c: i64, b: &osmformat::PrimitiveBlock) -> i32 {
-    let granularity = b.get_granularity() as i64;
-    ((b.get_lon_offset() + granularity * c) / 100) as i32
+    round_nanodeg_to_decimicro(nanodeg_lon(c, b))
 }
 
 fn make_tags(keys: &[u32], vals: &[u32], b: &PrimitiveBlock) -> Tags {
@@ -379,3 +966,32 @@ fn make_info(i: &osmformat::Info, b: &PrimitiveBlock) -> Info {
         visible,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_tag_span_end_stops_at_terminator() {
+        let keys_vals = [1, 2, 0, 3, 4];
+        assert_eq!(dense_tag_span_end(&keys_vals, 0), 2);
+    }
+
+    #[test]
+    fn dense_tag_span_end_clamps_on_unterminated_tail() {
+        // A malformed block can end mid-pair with no terminating 0; the scan
+        // must stop at the slice end instead of overshooting it.
+        let keys_vals = [1, 2, 3];
+        assert_eq!(dense_tag_span_end(&keys_vals, 0), 3);
+    }
+
+    #[test]
+    fn round_nanodeg_to_decimicro_rounds_ties_away_from_zero() {
+        assert_eq!(round_nanodeg_to_decimicro(149), 1);
+        assert_eq!(round_nanodeg_to_decimicro(150), 2);
+        assert_eq!(round_nanodeg_to_decimicro(-149), -1);
+        assert_eq!(round_nanodeg_to_decimicro(-150), -2);
+        assert_eq!(round_nanodeg_to_decimicro(50), 1);
+        assert_eq!(round_nanodeg_to_decimicro(-50), -1);
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2014-2015 Guillaume Pinot <texitoi(a)texitoi.eu>
+//
+// This work is free. You can redistribute it and/or modify it under
+// the terms of the Do What The Fuck You Want To Public License,
+// Version 2, as published by Sam Hocevar. See the COPYING file for
+// more details.
+
+use smartstring::alias::String;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub i64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WayId(pub i64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelationId(pub i64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OsmId {
+    Node(NodeId),
+    Way(WayId),
+    Relation(RelationId),
+}
+
+impl From<NodeId> for OsmId {
+    fn from(id: NodeId) -> Self {
+        OsmId::Node(id)
+    }
+}
+impl From<WayId> for OsmId {
+    fn from(id: WayId) -> Self {
+        OsmId::Way(id)
+    }
+}
+impl From<RelationId> for OsmId {
+    fn from(id: RelationId) -> Self {
+        OsmId::Relation(id)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Tags(BTreeMap<String, String>);
+
+impl Tags {
+    pub fn new() -> Self {
+        Tags(BTreeMap::new())
+    }
+    pub fn insert(&mut self, k: String, v: String) -> Option<String> {
+        self.0.insert(k, v)
+    }
+    pub fn shrink_to_fit(&mut self) {}
+}
+
+impl FromIterator<(String, String)> for Tags {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Tags(BTreeMap::from_iter(iter))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Info {
+    pub version: Option<i32>,
+    pub timestamp: Option<i64>,
+    pub changeset: Option<i64>,
+    pub uid: Option<i32>,
+    pub user: Option<String>,
+    pub visible: Option<bool>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Ref {
+    pub member: OsmId,
+    pub role: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    pub id: NodeId,
+    pub decimicro_lat: i32,
+    pub decimicro_lon: i32,
+    pub tags: Tags,
+    pub info: Info,
+    /// Full-precision nanodegree coordinates, kept alongside the rounded
+    /// `decimicro_lat`/`decimicro_lon` fields so callers can opt into exact
+    /// positions via [`Node::nanodeg_lat`]/[`Node::lat_f64`] without losing
+    /// the existing API.
+    pub(crate) nanodeg_lat: i64,
+    pub(crate) nanodeg_lon: i64,
+}
+
+impl Node {
+    /// Exact latitude in nanodegrees, with no precision lost to the
+    /// `decimicro_lat` rounding.
+    pub fn nanodeg_lat(&self) -> i64 {
+        self.nanodeg_lat
+    }
+
+    /// Exact longitude in nanodegrees, see [`Node::nanodeg_lat`].
+    pub fn nanodeg_lon(&self) -> i64 {
+        self.nanodeg_lon
+    }
+
+    /// Exact latitude in degrees, computed from `nanodeg_lat`.
+    pub fn lat_f64(&self) -> f64 {
+        self.nanodeg_lat as f64 / 1_000_000_000.0
+    }
+
+    /// Exact longitude in degrees, computed from `nanodeg_lon`.
+    pub fn lon_f64(&self) -> f64 {
+        self.nanodeg_lon as f64 / 1_000_000_000.0
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Way {
+    pub id: WayId,
+    pub nodes: Vec<NodeId>,
+    pub tags: Tags,
+    pub info: Info,
+}
+
+#[derive(Clone, Debug)]
+pub struct Relation {
+    pub id: RelationId,
+    pub refs: Vec<Ref>,
+    pub tags: Tags,
+    pub info: Info,
+}
+
+#[derive(Clone, Debug)]
+pub enum OsmObj {
+    Node(Node),
+    Way(Way),
+    Relation(Relation),
+}
+
+impl From<Node> for OsmObj {
+    fn from(n: Node) -> Self {
+        OsmObj::Node(n)
+    }
+}
+impl From<Way> for OsmObj {
+    fn from(w: Way) -> Self {
+        OsmObj::Way(w)
+    }
+}
+impl From<Relation> for OsmObj {
+    fn from(r: Relation) -> Self {
+        OsmObj::Relation(r)
+    }
+}